@@ -20,8 +20,8 @@ macro_rules! impl_put_bytes {
         let pos = $this.write_pos;
         let slice_len = $value.len();
         let buf_len = $this.buffer.len();
-        if pos + slice_len >= buf_len {
-            $this.buffer.resize(buf_len * 2, 0u8);
+        if pos + slice_len > buf_len {
+            $this.buffer.resize(cmp::max(buf_len * 2, pos + slice_len), 0u8);
         }
 
         $this.buffer[pos..pos + slice_len].copy_from_slice($value);
@@ -83,36 +83,72 @@ impl Bytes {
         impl_get_bytes!(self, i16, i16::from_be_bytes)
     }
 
+    /// Attempts to return a little-endian signed short from the reader, incrementing the position by `2` if successful.
+    /// Otherwise an error is returned if not enough bytes remain.
+    pub fn get_i16_le(&mut self) -> io::Result<i16> {
+        impl_get_bytes!(self, i16, i16::from_le_bytes)
+    }
+
     /// Attempts to return an unsigned short from the reader, incrementing the position by `2` if successful. Otherwise
     /// an error is returned if not enough bytes remain.
     pub fn get_u16(&mut self) -> io::Result<u16> {
         impl_get_bytes!(self, u16, u16::from_be_bytes)
     }
 
+    /// Attempts to return a little-endian unsigned short from the reader, incrementing the position by `2` if successful.
+    /// Otherwise an error is returned if not enough bytes remain.
+    pub fn get_u16_le(&mut self) -> io::Result<u16> {
+        impl_get_bytes!(self, u16, u16::from_le_bytes)
+    }
+
     /// Attempts to return a signed integer from the reader, incrementing the position by `4` if successful. Otherwise
     /// an error is returned if not enough bytes remain.
     pub fn get_i32(&mut self) -> io::Result<i32> {
         impl_get_bytes!(self, i32, i32::from_be_bytes)
     }
 
+    /// Attempts to return a little-endian signed integer from the reader, incrementing the position by `4` if successful.
+    /// Otherwise an error is returned if not enough bytes remain.
+    pub fn get_i32_le(&mut self) -> io::Result<i32> {
+        impl_get_bytes!(self, i32, i32::from_le_bytes)
+    }
+
     /// Attempts to return an unsigned integer from the reader, incrementing the position by `4` if successful. Otherwise
     /// an error is returned if not enough bytes remain.
     pub fn get_u32(&mut self) -> io::Result<u32> {
         impl_get_bytes!(self, u32, u32::from_be_bytes)
     }
 
+    /// Attempts to return a little-endian unsigned integer from the reader, incrementing the position by `4` if successful.
+    /// Otherwise an error is returned if not enough bytes remain.
+    pub fn get_u32_le(&mut self) -> io::Result<u32> {
+        impl_get_bytes!(self, u32, u32::from_le_bytes)
+    }
+
     /// Attempts to return a signed long from the reader, incrementing the position by `8` if successful. Otherwise
     /// an error is returned if not enough bytes remain.
     pub fn get_i64(&mut self) -> io::Result<i64> {
         impl_get_bytes!(self, i64, i64::from_be_bytes)
     }
 
+    /// Attempts to return a little-endian signed long from the reader, incrementing the position by `8` if successful.
+    /// Otherwise an error is returned if not enough bytes remain.
+    pub fn get_i64_le(&mut self) -> io::Result<i64> {
+        impl_get_bytes!(self, i64, i64::from_le_bytes)
+    }
+
     /// Attempts to return an unsigned long from the reader, incrementing the position by `8` if successful. Otherwise
     /// an error is returned if not enough bytes remain.
     pub fn get_u64(&mut self) -> io::Result<u64> {
         impl_get_bytes!(self, u64, u64::from_be_bytes)
     }
 
+    /// Attempts to return a little-endian unsigned long from the reader, incrementing the position by `8` if successful.
+    /// Otherwise an error is returned if not enough bytes remain.
+    pub fn get_u64_le(&mut self) -> io::Result<u64> {
+        impl_get_bytes!(self, u64, u64::from_le_bytes)
+    }
+
     /// Writes an unsigned byte value into the buffer, incrementing the position by `1`.
     pub fn put_u8(&mut self, value: u8) {
         let slice = &u8::to_be_bytes(value);
@@ -131,34 +167,68 @@ impl Bytes {
         impl_put_bytes!(self, slice);
     }
 
+    /// Writes a little-endian signed short value into the buffer, incrementing the position by `2`.
+    pub fn put_i16_le(&mut self, value: i16) {
+        let slice = &i16::to_le_bytes(value);
+        impl_put_bytes!(self, slice);
+    }
+
     /// Writes an unsigned short value into the buffer, incrementing the position by `2`.
     pub fn put_u16(&mut self, value: u16) {
         let slice: &[u8; 2] = &u16::to_be_bytes(value);
         impl_put_bytes!(self, slice);
     }
 
+    /// Writes a little-endian unsigned short value into the buffer, incrementing the position by `2`.
+    pub fn put_u16_le(&mut self, value: u16) {
+        let slice: &[u8; 2] = &u16::to_le_bytes(value);
+        impl_put_bytes!(self, slice);
+    }
+
     /// Writes a signed int value into the buffer, incrementing the position by `4`.
     pub fn put_i32(&mut self, value: i32) {
         let slice = &i32::to_be_bytes(value);
         impl_put_bytes!(self, slice);
     }
 
+    /// Writes a little-endian signed int value into the buffer, incrementing the position by `4`.
+    pub fn put_i32_le(&mut self, value: i32) {
+        let slice = &i32::to_le_bytes(value);
+        impl_put_bytes!(self, slice);
+    }
+
     /// Writes an unsigned int value into the buffer, incrementing the position by `4`.
     pub fn put_u32(&mut self, value: u32) {
         let slice = &u32::to_be_bytes(value);
         impl_put_bytes!(self, slice);
     }
 
+    /// Writes a little-endian unsigned int value into the buffer, incrementing the position by `4`.
+    pub fn put_u32_le(&mut self, value: u32) {
+        let slice = &u32::to_le_bytes(value);
+        impl_put_bytes!(self, slice);
+    }
+
     /// Writes an unsigned int value into the buffer, incrementing the position by `8`.
     pub fn put_u64(&mut self, value: u64) {
         let slice = &u64::to_be_bytes(value);
         impl_put_bytes!(self, slice);
     }
 
+    /// Writes a little-endian unsigned long value into the buffer, incrementing the position by `8`.
+    pub fn put_u64_le(&mut self, value: u64) {
+        let slice = &u64::to_le_bytes(value);
+        impl_put_bytes!(self, slice);
+    }
+
     pub fn len(&self) -> usize {
         self.buffer.len()
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
     pub fn readable(&self) -> usize {
         self.buffer.len() - self.read_pos
     }
@@ -183,3 +253,63 @@ impl Deref for Bytes {
         self.buffer.deref()
     }
 }
+
+/// Reads from this buffer starting at `read_pos`, so it can be passed anywhere the `bytes` crate's
+/// `Buf` abstraction is expected (chained adapters, `reader()`, downstream network code, ...).
+impl ::bytes::Buf for Bytes {
+    fn remaining(&self) -> usize {
+        self.readable()
+    }
+
+    fn chunk(&self) -> &[u8] {
+        &self.buffer[self.read_pos..]
+    }
+
+    fn advance(&mut self, cnt: usize) {
+        self.advance_read_pos(cnt);
+    }
+}
+
+/// Writes into this buffer starting at `write_pos`, growing the backing storage as needed, so it
+/// can be passed anywhere the `bytes` crate's `BufMut` abstraction is expected.
+unsafe impl ::bytes::BufMut for Bytes {
+    fn remaining_mut(&self) -> usize {
+        usize::MAX - self.write_pos
+    }
+
+    unsafe fn advance_mut(&mut self, cnt: usize) {
+        let new_write_pos = self.write_pos + cnt;
+        if new_write_pos > self.buffer.len() {
+            self.buffer.resize(new_write_pos, 0);
+        }
+        self.write_pos = new_write_pos;
+    }
+
+    fn chunk_mut(&mut self) -> &mut ::bytes::buf::UninitSlice {
+        if self.write_pos == self.buffer.len() {
+            let additional = cmp::max(64, self.buffer.len());
+            self.buffer.resize(self.buffer.len() + additional, 0);
+        }
+        ::bytes::buf::UninitSlice::new(&mut self.buffer[self.write_pos..])
+    }
+}
+
+/// When the `explicit_clear` feature is enabled, overwrites the backing storage with zeros on
+/// drop so plaintext/ciphertext/key-derived bytes don't linger in freed memory. The write is
+/// volatile so the optimizer can't elide it.
+#[cfg(feature = "explicit_clear")]
+impl Bytes {
+    pub(crate) fn clear(&mut self) {
+        for byte in self.buffer.iter_mut() {
+            unsafe { std::ptr::write_volatile(byte, 0) };
+        }
+        std::sync::atomic::compiler_fence(std::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+#[cfg(feature = "explicit_clear")]
+impl Drop for Bytes {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
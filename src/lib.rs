@@ -2,6 +2,7 @@ use bytes::Bytes;
 use std::io;
 
 pub mod bytes;
+pub mod stream;
 
 /// A 128-bit key used by an [Xtea] instance when processing the block cipher.
 #[derive(Clone, Debug)]
@@ -15,13 +16,82 @@ impl std::ops::Index<usize> for XteaKey {
     }
 }
 
+/// When the `explicit_clear` feature is enabled, overwrites the key material with zeros on drop
+/// so it doesn't linger in freed memory. The write is volatile so the optimizer can't elide it.
+#[cfg(feature = "explicit_clear")]
+impl XteaKey {
+    fn clear(&mut self) {
+        for word in self.0.iter_mut() {
+            unsafe { std::ptr::write_volatile(word, 0) };
+        }
+        std::sync::atomic::compiler_fence(std::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+#[cfg(feature = "explicit_clear")]
+impl Drop for XteaKey {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+/// Selects the block-cipher mode of operation applied on top of the raw XTEA block primitive.
+///
+/// The default is [Mode::Ecb], which processes each block independently. Every other mode
+/// chains blocks together using the IV configured via [Xtea::with_iv] and should be preferred
+/// whenever more than one block of identical plaintext may be enciphered under the same key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+    /// Electronic codebook: each block is enciphered independently of every other block.
+    Ecb,
+    /// Cipher block chaining: each plaintext block is XORed with the previous ciphertext block
+    /// (or the IV, for the first block) before being enciphered.
+    Cbc,
+    /// Cipher feedback: the previous ciphertext block (or the IV) is enciphered and XORed with
+    /// the plaintext to produce the ciphertext.
+    Cfb,
+    /// Output feedback: the IV is repeatedly enciphered to build a keystream that is independent
+    /// of the plaintext and ciphertext.
+    Ofb,
+    /// Counter mode: a counter block, starting at the IV/nonce, is enciphered to build a
+    /// keystream and is incremented after every block. Encryption and decryption are identical.
+    Ctr,
+}
+
+/// Selects the padding scheme applied to data that isn't a multiple of the 8-byte block size.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Padding {
+    /// Pads with `n` bytes each equal to `n`, where `n = 8 - (len % 8)`. A full block of padding
+    /// is appended when the input is already block-aligned, so the padding can always be removed
+    /// unambiguously.
+    Pkcs7,
+    /// Performs no padding. [Xtea::encipher] returns an error if the input isn't a multiple of 8
+    /// bytes, and [Xtea::decipher] returns the deciphered blocks as-is.
+    NoPadding,
+}
+
+/// Selects the byte order used to pack/unpack the two 32-bit words of a block.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Endian {
+    /// Packs each word big-endian. This is the historical default of this crate.
+    Big,
+    /// Packs each word little-endian, matching most non-Rust XTEA implementations (game
+    /// protocols, embedded C code).
+    Little,
+}
+
 /// An Xtea data structure equipped to perform the [eXtended TEA](https://en.wikipedia.org/wiki/XTEA) block cipher. Each XTEA
-/// instance takes a 128-bit key represented in the form of [XteaKey], applying a pseudorandom permutation on passed-in data 
+/// instance takes a 128-bit key represented in the form of [XteaKey], applying a pseudorandom permutation on passed-in data
 /// in 64-bit chunks, commonly referred to as "blocks".
 #[derive(Debug)]
 pub struct Xtea {
     key: XteaKey,
     rounds: u32,
+    mode: Mode,
+    iv: [u32; 2],
+    padding: Padding,
+    endianness: Endian,
+    chunk_size: usize,
 }
 
 impl Xtea {
@@ -30,12 +100,26 @@ impl Xtea {
 
     const DELTA: u32 = 0x9E3779B9;
 
+    /// The default chunk size used by [Xtea::seal] when none is configured via [Xtea::with_chunk_size].
+    pub const DEFAULT_CHUNK_SIZE: usize = 64 * 1024;
+
+    /// The smallest chunk size accepted by [Xtea::with_chunk_size].
+    pub const MIN_CHUNK_SIZE: usize = 64;
+
+    /// The largest chunk size accepted by [Xtea::with_chunk_size].
+    pub const MAX_CHUNK_SIZE: usize = 16 * 1024 * 1024;
+
     /// Assigns a 128-bit key using the passed-in array of 32-bit integers.
     pub fn using_key(key: [u32; 4]) -> Self {
         assert!(key.len() == 4);
         Self {
             key: XteaKey(key.to_vec()),
             rounds: Self::DEFAULT_ROUNDS,
+            mode: Mode::Ecb,
+            iv: [0, 0],
+            padding: Padding::Pkcs7,
+            endianness: Endian::Big,
+            chunk_size: Self::DEFAULT_CHUNK_SIZE,
         }
     }
 
@@ -46,14 +130,300 @@ impl Xtea {
         self
     }
 
-    /// Encrypts the supplied `input` data and writes the processed results to the `output` array.
-    pub fn encipher(&self, mut input: &[u8]) -> io::Result<Vec<u8>> {
-        self.do_block_cipher(&mut input, &mut output, false)
+    /// Selects the block-cipher mode of operation used when chaining blocks together. Defaults to [Mode::Ecb].
+    pub fn with_mode(mut self, mode: Mode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Specifies the 64-bit initialization vector (or nonce, for [Mode::Ctr]) used by every mode other than
+    /// [Mode::Ecb]. Defaults to `[0, 0]`.
+    pub fn with_iv(mut self, iv: [u32; 2]) -> Self {
+        self.iv = iv;
+        self
+    }
+
+    /// Selects the padding scheme applied to data that isn't a multiple of the 8-byte block size. Defaults
+    /// to [Padding::Pkcs7].
+    pub fn with_padding(mut self, padding: Padding) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Selects the byte order used to pack/unpack each block's two 32-bit words. Defaults to [Endian::Big].
+    pub fn with_endianness(mut self, endianness: Endian) -> Self {
+        self.endianness = endianness;
+        self
     }
 
-    /// Decrypts the supplied encrypted `input` array and writes the processed results to the `output` array.
+    /// Specifies the chunk size used by [Xtea::seal]/[Xtea::open], in bytes. Defaults to
+    /// [Xtea::DEFAULT_CHUNK_SIZE]. Must be within [Xtea::MIN_CHUNK_SIZE]..=[Xtea::MAX_CHUNK_SIZE].
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        assert!(
+            (Self::MIN_CHUNK_SIZE..=Self::MAX_CHUNK_SIZE).contains(&chunk_size),
+            "chunk_size must be between {} and {} bytes",
+            Self::MIN_CHUNK_SIZE,
+            Self::MAX_CHUNK_SIZE
+        );
+        self.chunk_size = chunk_size;
+        self
+    }
+
+    /// Encrypts the supplied `input` data, returning the enciphered result.
+    pub fn encipher(&self, input: &[u8]) -> io::Result<Vec<u8>> {
+        let padded = self.pad(input)?;
+        self.do_block_cipher(&padded, false)
+    }
+
+    /// Decrypts the supplied encrypted `input` data, returning the deciphered result.
     pub fn decipher(&self, input: &[u8]) -> io::Result<Vec<u8>> {
-        self.do_block_cipher(input, output, true)
+        let output = self.do_block_cipher(input, true)?;
+        self.unpad(output)
+    }
+
+    /// Applies the configured [Padding] scheme to `input`, returning a block-aligned buffer.
+    fn pad(&self, input: &[u8]) -> io::Result<Vec<u8>> {
+        match self.padding {
+            Padding::NoPadding => {
+                if !input.len().is_multiple_of(8) {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        "input length must be a multiple of 8 bytes when using Padding::NoPadding",
+                    ));
+                }
+                Ok(input.to_vec())
+            }
+            Padding::Pkcs7 => {
+                let pad_len = 8 - (input.len() % 8);
+                let mut padded = Vec::with_capacity(input.len() + pad_len);
+                padded.extend_from_slice(input);
+                padded.extend(std::iter::repeat_n(pad_len as u8, pad_len));
+                Ok(padded)
+            }
+        }
+    }
+
+    /// Removes the configured [Padding] scheme from a freshly deciphered `output` buffer.
+    fn unpad(&self, mut output: Vec<u8>) -> io::Result<Vec<u8>> {
+        match self.padding {
+            Padding::NoPadding => Ok(output),
+            Padding::Pkcs7 => {
+                let pad_len = *output
+                    .last()
+                    .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "empty output cannot contain PKCS#7 padding"))?
+                    as usize;
+
+                if pad_len == 0 || pad_len > 8 || pad_len > output.len() {
+                    return Err(io::Error::new(io::ErrorKind::InvalidData, "invalid PKCS#7 padding"));
+                }
+                if !output[output.len() - pad_len..].iter().all(|&b| b as usize == pad_len) {
+                    return Err(io::Error::new(io::ErrorKind::InvalidData, "invalid PKCS#7 padding"));
+                }
+
+                output.truncate(output.len() - pad_len);
+                Ok(output)
+            }
+        }
+    }
+
+    /// Seals `plaintext` in an AEAD-style construction: the data is split into fixed-size chunks
+    /// (see [Xtea::with_chunk_size]), each chunk is encrypted in CTR mode under a per-chunk
+    /// counter derived from `nonce` and the chunk index, and a keyed tag covering the chunk's
+    /// ciphertext, index, the total plaintext length and `associated_data` is appended. The chunk size and plaintext length
+    /// are framed into a 12-byte header so [Xtea::open] can walk the chunks without guessing their
+    /// boundaries. This only uses the key and rounds configured on this `Xtea` — the configured
+    /// [Mode]/[Padding]/[Endian]/IV have no effect on `seal`/`open`.
+    pub fn seal(&self, nonce: [u32; 2], associated_data: &[u8], plaintext: &[u8]) -> io::Result<Vec<u8>> {
+        let chunk_count = plaintext.len() / self.chunk_size + 1;
+        let mut sealed = Bytes::new(Vec::with_capacity(12 + plaintext.len() + chunk_count * 8));
+        sealed.buffer.extend_from_slice(&(self.chunk_size as u32).to_be_bytes());
+        sealed.buffer.extend_from_slice(&(plaintext.len() as u64).to_be_bytes());
+
+        for (index, chunk) in plaintext.chunks(self.chunk_size).enumerate() {
+            let counter = Self::chunk_counter(nonce, index as u64);
+            let ciphertext = self.ctr_xor(chunk, counter);
+            let tag = self.compute_tag(associated_data, plaintext.len() as u64, index as u64, &ciphertext);
+
+            sealed.buffer.extend_from_slice(&ciphertext);
+            sealed.buffer.extend_from_slice(&tag[0].to_be_bytes());
+            sealed.buffer.extend_from_slice(&tag[1].to_be_bytes());
+        }
+
+        Ok(std::mem::take(&mut sealed.buffer))
+    }
+
+    /// Opens data previously produced by [Xtea::seal], verifying each chunk's tag before
+    /// releasing its plaintext. Returns an `io::Error` as soon as a tag mismatch or truncation is
+    /// detected, so tampering is caught before any unauthenticated plaintext is returned.
+    pub fn open(&self, nonce: [u32; 2], associated_data: &[u8], sealed: &[u8]) -> io::Result<Vec<u8>> {
+        let mut header = Bytes::new(sealed.to_vec());
+        let chunk_size = header.get_u32()? as usize;
+        let total_len = header.get_u64()? as usize;
+
+        if chunk_size == 0 {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "sealed chunk size must be non-zero"));
+        }
+
+        // `total_len` comes straight from the (attacker-controlled) header, so it must be
+        // sanity-checked against the actual input size before it's used to size an allocation —
+        // otherwise a forged header can abort the process with a capacity overflow instead of
+        // returning a handled error. CTR keeps ciphertext and plaintext lengths equal, so the
+        // plaintext can never be longer than the remaining sealed bytes after the header.
+        if total_len > sealed.len().saturating_sub(12) {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "sealed total length exceeds input size"));
+        }
+
+        let mut plaintext = Bytes::new(Vec::with_capacity(total_len));
+        let mut remaining = total_len;
+        let mut offset = 12;
+        let mut index = 0u64;
+
+        while remaining > 0 {
+            let take = std::cmp::min(remaining, chunk_size);
+            let ciphertext_end = offset + take;
+            if ciphertext_end + 8 > sealed.len() {
+                return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "truncated sealed chunk"));
+            }
+
+            let ciphertext = &sealed[offset..ciphertext_end];
+            let expected_tag = self.compute_tag(associated_data, total_len as u64, index, ciphertext);
+
+            let mut tag_reader = Bytes::new(sealed[ciphertext_end..ciphertext_end + 8].to_vec());
+            let actual_tag = [tag_reader.get_u32()?, tag_reader.get_u32()?];
+
+            if actual_tag != expected_tag {
+                return Err(io::Error::new(io::ErrorKind::InvalidData, "authentication tag mismatch"));
+            }
+
+            let counter = Self::chunk_counter(nonce, index);
+            plaintext.buffer.extend_from_slice(&self.ctr_xor(ciphertext, counter));
+
+            offset = ciphertext_end + 8;
+            remaining -= take;
+            index += 1;
+        }
+
+        Ok(std::mem::take(&mut plaintext.buffer))
+    }
+
+    /// Derives the starting CTR counter block for chunk `chunk_index` by adding it onto `nonce`
+    /// as a 64-bit value, with carry across the two `u32` words.
+    fn chunk_counter(nonce: [u32; 2], chunk_index: u64) -> [u32; 2] {
+        let mut counter = nonce;
+        let (low, carry) = counter[1].overflowing_add(chunk_index as u32);
+        counter[1] = low;
+        counter[0] = counter[0]
+            .wrapping_add((chunk_index >> 32) as u32)
+            .wrapping_add(carry as u32);
+        counter
+    }
+
+    /// XORs `data` against the CTR keystream produced by repeatedly enciphering `counter_start`
+    /// and incrementing it (with carry) after every block. The result is staged in a [Bytes]
+    /// buffer (rather than a bare `Vec<u8>`) so that, with the `explicit_clear` feature enabled,
+    /// this secret-derived keystream/plaintext material is scrubbed on drop.
+    fn ctr_xor(&self, data: &[u8], counter_start: [u32; 2]) -> Bytes {
+        let mut counter = counter_start;
+        let mut output = Bytes::new(Vec::with_capacity(data.len()));
+
+        for chunk in data.chunks(8) {
+            let mut keystream_words = [0_u32; 2];
+            self.encipher_block(&counter, &mut keystream_words);
+
+            let mut keystream = [0u8; 8];
+            keystream[0..4].copy_from_slice(&keystream_words[0].to_be_bytes());
+            keystream[4..8].copy_from_slice(&keystream_words[1].to_be_bytes());
+
+            for (i, &byte) in chunk.iter().enumerate() {
+                output.buffer.push(byte ^ keystream[i]);
+            }
+
+            counter[1] = counter[1].wrapping_add(1);
+            if counter[1] == 0 {
+                counter[0] = counter[0].wrapping_add(1);
+            }
+        }
+
+        output
+    }
+
+    /// Doubles a 64-bit subkey in `GF(2^64)` (reduction polynomial `x^64 + x^4 + x^3 + x + 1`),
+    /// the subkey-derivation step of the CMAC/OMAC1 construction (NIST SP 800-38B).
+    fn double_subkey(block: [u32; 2]) -> [u32; 2] {
+        let msb_set = block[0] & 0x8000_0000 != 0;
+        let mut doubled = [(block[0] << 1) | (block[1] >> 31), block[1] << 1];
+        if msb_set {
+            doubled[1] ^= 0x1B;
+        }
+        doubled
+    }
+
+    /// Derives the two CMAC subkeys from enciphering the all-zero block under this `Xtea`'s key.
+    fn subkeys(&self) -> ([u32; 2], [u32; 2]) {
+        let mut l = [0_u32; 2];
+        self.encipher_block(&[0, 0], &mut l);
+        let k1 = Self::double_subkey(l);
+        let k2 = Self::double_subkey(k1);
+        (k1, k2)
+    }
+
+    /// Computes the keyed authentication tag for one chunk using CMAC/OMAC1 (NIST SP 800-38B)
+    /// over the total plaintext length, the chunk index, `associated_data` and `ciphertext` under
+    /// this `Xtea`'s key. Covering the total length means a header rewritten to drop trailing
+    /// chunks fails every remaining tag. Unlike
+    /// plain CBC-MAC, this construction is secure for variable-length, multi-message input under
+    /// one key: the final block is tweaked with a subkey derived from the cipher itself (`K1` for
+    /// a complete final block, `K2` plus 10* padding otherwise), which is what blocks the
+    /// classic CBC-MAC length-extension forgery.
+    fn compute_tag(&self, associated_data: &[u8], total_len: u64, chunk_index: u64, ciphertext: &[u8]) -> [u32; 2] {
+        let mut message = Bytes::new(Vec::with_capacity(16 + associated_data.len() + ciphertext.len()));
+        message.buffer.extend_from_slice(&total_len.to_be_bytes());
+        message.buffer.extend_from_slice(&chunk_index.to_be_bytes());
+        message.buffer.extend_from_slice(associated_data);
+        message.buffer.extend_from_slice(ciphertext);
+
+        let (k1, k2) = self.subkeys();
+        let is_complete_block = !message.buffer.is_empty() && message.buffer.len().is_multiple_of(8);
+        let block_count = if message.buffer.is_empty() {
+            1
+        } else {
+            message.buffer.len().div_ceil(8)
+        };
+
+        let mut state = [0_u32; 2];
+        for index in 0..block_count {
+            let start = index * 8;
+            let is_last = index == block_count - 1;
+
+            let mut block_words = if is_last {
+                let mut last_block = [0u8; 8];
+                if is_complete_block {
+                    last_block.copy_from_slice(&message.buffer[start..start + 8]);
+                } else {
+                    let remaining = &message.buffer[start..];
+                    last_block[..remaining.len()].copy_from_slice(remaining);
+                    last_block[remaining.len()] = 0x80;
+                }
+
+                let tweak = if is_complete_block { k1 } else { k2 };
+                [
+                    u32::from_be_bytes([last_block[0], last_block[1], last_block[2], last_block[3]]) ^ tweak[0],
+                    u32::from_be_bytes([last_block[4], last_block[5], last_block[6], last_block[7]]) ^ tweak[1],
+                ]
+            } else {
+                let block = &message.buffer[start..start + 8];
+                [
+                    u32::from_be_bytes([block[0], block[1], block[2], block[3]]),
+                    u32::from_be_bytes([block[4], block[5], block[6], block[7]]),
+                ]
+            };
+
+            block_words[0] ^= state[0];
+            block_words[1] ^= state[1];
+            self.encipher_block(&block_words, &mut state);
+        }
+
+        state
     }
 
     fn encipher_block(&self, input: &[u32; 2], output: &mut [u32; 2]) {
@@ -61,13 +431,14 @@ impl Xtea {
         let mut v1 = input[1];
         let mut sum = 0u32;
 
-        
         for _ in 0..self.rounds {
-            v0 = v0.wrapping_add(((v1 << 4) ^ (v1 >> 5)).wrapping_add(v1))
-                ^ (sum.wrapping_add(self.key[(sum & 3) as usize]));
+            v0 = v0.wrapping_add(
+                ((v1 << 4) ^ (v1 >> 5)).wrapping_add(v1)
+                    ^ (sum.wrapping_add(self.key[(sum & 3) as usize])),
+            );
             sum = sum.wrapping_add(Self::DELTA);
             v1 = v1.wrapping_add(
-                (((v0 << 4) ^ (v0 >> 5)).wrapping_add(v0))
+                ((v0 << 4) ^ (v0 >> 5)).wrapping_add(v0)
                     ^ (sum.wrapping_add(self.key[((sum >> 11) & 3) as usize])),
             );
         }
@@ -83,12 +454,12 @@ impl Xtea {
 
         for _ in 0..self.rounds {
             v1 = v1.wrapping_sub(
-                (((v0 << 4) ^ (v0 >> 5)).wrapping_add(v0))
+                ((v0 << 4) ^ (v0 >> 5)).wrapping_add(v0)
                     ^ (sum.wrapping_add(self.key[((sum >> 11) & 3) as usize])),
             );
             sum = sum.wrapping_sub(Self::DELTA);
             v0 = v0.wrapping_sub(
-                (((v1 << 4) ^ (v1 >> 5)).wrapping_add(v1))
+                ((v1 << 4) ^ (v1 >> 5)).wrapping_add(v1)
                     ^ (sum.wrapping_add(self.key[(sum & 3) as usize])),
             );
         }
@@ -97,26 +468,304 @@ impl Xtea {
         output[1] = v1;
     }
 
+    /// Processes a single block through the configured [Mode], advancing `register` (the IV,
+    /// previous ciphertext block, feedback register or counter, depending on the mode) in place.
+    /// Shared by [Xtea::do_block_cipher] and the streaming wrappers in [crate::stream] so both
+    /// operate identically block-by-block.
+    fn process_block(&self, input: [u32; 2], register: &mut [u32; 2], decrypt: bool) -> [u32; 2] {
+        let mut output = [0_u32; 2];
+
+        match self.mode {
+            Mode::Ecb => {
+                if decrypt {
+                    self.decipher_block(&input, &mut output);
+                } else {
+                    self.encipher_block(&input, &mut output);
+                }
+            }
+            Mode::Cbc => {
+                if decrypt {
+                    self.decipher_block(&input, &mut output);
+                    output[0] ^= register[0];
+                    output[1] ^= register[1];
+                    *register = input;
+                } else {
+                    let chained = [input[0] ^ register[0], input[1] ^ register[1]];
+                    self.encipher_block(&chained, &mut output);
+                    *register = output;
+                }
+            }
+            Mode::Cfb => {
+                let mut keystream = [0_u32; 2];
+                self.encipher_block(register, &mut keystream);
+                output[0] = input[0] ^ keystream[0];
+                output[1] = input[1] ^ keystream[1];
+                *register = if decrypt { input } else { output };
+            }
+            Mode::Ofb => {
+                let mut keystream = [0_u32; 2];
+                self.encipher_block(register, &mut keystream);
+                output[0] = input[0] ^ keystream[0];
+                output[1] = input[1] ^ keystream[1];
+                *register = keystream;
+            }
+            Mode::Ctr => {
+                let mut keystream = [0_u32; 2];
+                self.encipher_block(register, &mut keystream);
+                output[0] = input[0] ^ keystream[0];
+                output[1] = input[1] ^ keystream[1];
+                register[1] = register[1].wrapping_add(1);
+                if register[1] == 0 {
+                    register[0] = register[0].wrapping_add(1);
+                }
+            }
+        }
+
+        output
+    }
+
     fn do_block_cipher(&self, input: &[u8], decrypt: bool) -> io::Result<Vec<u8>> {
         let mut input_buffer = Bytes::new(input.to_vec());
         let mut output_buffer = Bytes::new(vec![0; input.len()]);
-        let mut input_slice = [0_u32; 2];
-        let mut output_slice = [0_u32; 2];
+        let mut register = self.iv;
         let iterations = input_buffer.readable() / 8;
 
         for _ in 0..iterations {
-            input_slice[0] = input_buffer.get_u32()?;
-            input_slice[1] = input_buffer.get_u32()?;
+            let input_slice = self.read_block(&mut input_buffer)?;
 
-            if decrypt {
-                self.decipher_block(&input_slice, &mut output_slice);
-            } else {
-                self.encipher_block(&input_slice, &mut output_slice);
+            let output_slice = self.process_block(input_slice, &mut register, decrypt);
+
+            self.write_block(&mut output_buffer, output_slice);
+        }
+        Ok(std::mem::take(&mut output_buffer.buffer))
+    }
+
+    /// Reads a block's two 32-bit words from `buffer`, honoring the configured [Endian].
+    fn read_block(&self, buffer: &mut Bytes) -> io::Result<[u32; 2]> {
+        match self.endianness {
+            Endian::Big => Ok([buffer.get_u32()?, buffer.get_u32()?]),
+            Endian::Little => Ok([buffer.get_u32_le()?, buffer.get_u32_le()?]),
+        }
+    }
+
+    /// Writes a block's two 32-bit words into `buffer`, honoring the configured [Endian].
+    fn write_block(&self, buffer: &mut Bytes, words: [u32; 2]) {
+        match self.endianness {
+            Endian::Big => {
+                buffer.put_u32(words[0]);
+                buffer.put_u32(words[1]);
+            }
+            Endian::Little => {
+                buffer.put_u32_le(words[0]);
+                buffer.put_u32_le(words[1]);
             }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: [u32; 4] = [0x0123_4567, 0x89AB_CDEF, 0xFEDC_BA98, 0x7654_3210];
+
+    #[test]
+    fn block_round_trip() {
+        let xtea = Xtea::using_key(KEY);
+        let input = [0x1122_3344, 0x5566_7788];
+        let mut ciphertext = [0_u32; 2];
+        xtea.encipher_block(&input, &mut ciphertext);
+        assert_ne!(ciphertext, input);
+
+        let mut plaintext = [0_u32; 2];
+        xtea.decipher_block(&ciphertext, &mut plaintext);
+        assert_eq!(plaintext, input);
+    }
+
+    #[test]
+    fn ecb_round_trip() {
+        let xtea = Xtea::using_key(KEY);
+        let data = b"ECB mode needs two full blocks.";
+        let ciphertext = xtea.encipher(data).unwrap();
+        assert_ne!(ciphertext, data);
+        assert_eq!(xtea.decipher(&ciphertext).unwrap(), data);
+    }
+
+    #[test]
+    fn cbc_round_trip() {
+        let xtea = Xtea::using_key(KEY).with_mode(Mode::Cbc).with_iv([0xDEAD_BEEF, 0xCAFE_BABE]);
+        let data = b"CBC chains ciphertext blocks together.";
+        let ciphertext = xtea.encipher(data).unwrap();
+        assert_eq!(xtea.decipher(&ciphertext).unwrap(), data);
+    }
 
-            output_buffer.put_u32(output_slice[0]);
-            output_buffer.put_u32(output_slice[1]);
+    #[test]
+    fn cfb_round_trip() {
+        let xtea = Xtea::using_key(KEY).with_mode(Mode::Cfb).with_iv([0x1111_1111, 0x2222_2222]);
+        let data = b"CFB turns the block cipher into a stream cipher.";
+        let ciphertext = xtea.encipher(data).unwrap();
+        assert_eq!(xtea.decipher(&ciphertext).unwrap(), data);
+    }
+
+    #[test]
+    fn ofb_round_trip() {
+        let xtea = Xtea::using_key(KEY).with_mode(Mode::Ofb).with_iv([0x3333_3333, 0x4444_4444]);
+        let data = b"OFB keystream is independent of the ciphertext.";
+        let ciphertext = xtea.encipher(data).unwrap();
+        assert_eq!(xtea.decipher(&ciphertext).unwrap(), data);
+    }
+
+    #[test]
+    fn ctr_round_trip() {
+        let xtea = Xtea::using_key(KEY).with_mode(Mode::Ctr).with_iv([0x5555_5555, 0x6666_6666]);
+        let data = b"CTR mode: encrypt and decrypt are identical.";
+        let ciphertext = xtea.encipher(data).unwrap();
+        assert_eq!(xtea.decipher(&ciphertext).unwrap(), data);
+    }
+
+    #[test]
+    fn ctr_increments_carry_across_words() {
+        // Start right at the edge of a low-word carry to exercise the counter overflow path.
+        let xtea = Xtea::using_key(KEY).with_mode(Mode::Ctr).with_iv([0, 0xFFFF_FFFF]);
+        let data = vec![0x42u8; 24];
+        let ciphertext = xtea.encipher(&data).unwrap();
+
+        // The second block's keystream must come from the carried counter `[1, 0]`.
+        let mut keystream = [0_u32; 2];
+        xtea.encipher_block(&[1, 0], &mut keystream);
+        let mut expected = [0u8; 8];
+        expected[0..4].copy_from_slice(&(0x4242_4242 ^ keystream[0]).to_be_bytes());
+        expected[4..8].copy_from_slice(&(0x4242_4242 ^ keystream[1]).to_be_bytes());
+        assert_eq!(&ciphertext[8..16], &expected);
+
+        assert_eq!(xtea.decipher(&ciphertext).unwrap(), data);
+    }
+
+    #[test]
+    fn pkcs7_padding_round_trips_every_length_up_to_two_blocks() {
+        let xtea = Xtea::using_key(KEY);
+        for len in 0..=16 {
+            let data = vec![0xAB; len];
+            let ciphertext = xtea.encipher(&data).unwrap();
+            assert_eq!(ciphertext.len() % 8, 0);
+            assert_eq!(xtea.decipher(&ciphertext).unwrap(), data, "length {len} failed to round-trip");
         }
-        Ok(output_buffer.buffer)
+    }
+
+    #[test]
+    fn pkcs7_padding_rejects_tampered_padding() {
+        let xtea = Xtea::using_key(KEY);
+        let mut ciphertext = xtea.encipher(b"tamper with the padding").unwrap();
+        let last = ciphertext.len() - 1;
+        ciphertext[last] ^= 0xFF;
+        assert!(xtea.decipher(&ciphertext).is_err());
+    }
+
+    #[test]
+    fn no_padding_requires_block_aligned_input() {
+        let xtea = Xtea::using_key(KEY).with_padding(Padding::NoPadding);
+        assert!(xtea.encipher(b"not aligned").is_err());
+
+        let aligned = b"aligned!";
+        let ciphertext = xtea.encipher(aligned).unwrap();
+        assert_eq!(xtea.decipher(&ciphertext).unwrap(), aligned);
+    }
+
+    #[test]
+    fn little_endian_interop_with_big_endian() {
+        let be = Xtea::using_key(KEY).with_padding(Padding::NoPadding);
+        let le = Xtea::using_key(KEY).with_padding(Padding::NoPadding).with_endianness(Endian::Little);
+
+        let data = b"BEvsLE!!";
+        let mut swapped = *data;
+        for word in swapped.chunks_mut(4) {
+            word.reverse();
+        }
+
+        // Reading a block's words little-endian is equivalent to reading the same block
+        // big-endian with each word's bytes reversed, so feeding the byte-swapped input to the
+        // little-endian instance must produce the byte-swapped ciphertext of the big-endian one.
+        let be_ciphertext = be.encipher(data).unwrap();
+        let le_ciphertext = le.encipher(&swapped).unwrap();
+
+        let mut le_reswapped = le_ciphertext.clone();
+        for word in le_reswapped.chunks_mut(4) {
+            word.reverse();
+        }
+        assert_eq!(be_ciphertext, le_reswapped);
+
+        assert_eq!(le.decipher(&le_ciphertext).unwrap(), swapped);
+    }
+
+    #[test]
+    fn seal_open_round_trip() {
+        let xtea = Xtea::using_key(KEY).with_chunk_size(Xtea::MIN_CHUNK_SIZE);
+        let nonce = [0x1234_5678, 0x9ABC_DEF0];
+        let aad = b"associated metadata";
+        let plaintext = b"This message is deliberately longer than one minimum-size AEAD chunk, so sealing it exercises chunking across several chunks of ciphertext and tags.";
+
+        let sealed = xtea.seal(nonce, aad, plaintext).unwrap();
+        assert_eq!(xtea.open(nonce, aad, &sealed).unwrap(), plaintext);
+    }
+
+    #[test]
+    fn seal_open_rejects_ciphertext_tampering() {
+        let xtea = Xtea::using_key(KEY);
+        let nonce = [0xAAAA_AAAA, 0xBBBB_BBBB];
+        let mut sealed = xtea.seal(nonce, b"", b"don't touch me").unwrap();
+
+        let last = sealed.len() - 1;
+        sealed[last] ^= 0x01;
+        assert!(xtea.open(nonce, b"", &sealed).is_err());
+    }
+
+    #[test]
+    fn seal_open_rejects_wrong_associated_data() {
+        let xtea = Xtea::using_key(KEY);
+        let nonce = [0x1, 0x2];
+        let sealed = xtea.seal(nonce, b"correct aad", b"secret").unwrap();
+        assert!(xtea.open(nonce, b"wrong aad", &sealed).is_err());
+    }
+
+    #[test]
+    fn seal_open_rejects_truncation() {
+        let xtea = Xtea::using_key(KEY);
+        let nonce = [0x9, 0x9];
+        let mut sealed = xtea.seal(nonce, b"", b"a fairly long secret message").unwrap();
+        sealed.truncate(sealed.len() - 4);
+        assert!(xtea.open(nonce, b"", &sealed).is_err());
+    }
+
+    #[test]
+    fn seal_open_rejects_forged_total_len() {
+        let xtea = Xtea::using_key(KEY);
+        let nonce = [0x9, 0x9];
+
+        // A 12-byte header claiming a huge `total_len` must be rejected as invalid input rather
+        // than attempting a `Vec::with_capacity(u64::MAX)` allocation.
+        let mut forged = Bytes::new(Vec::new());
+        forged.put_u32(64);
+        forged.put_u64(u64::MAX);
+
+        assert!(xtea.open(nonce, b"", &forged.buffer).is_err());
+    }
+
+    #[cfg(feature = "explicit_clear")]
+    #[test]
+    fn explicit_clear_zeroizes_key_on_drop() {
+        // `Drop::drop` and this test both call the same `clear` routine, so exercising it
+        // directly on a still-live value verifies the real zeroizing behavior without reading
+        // memory that has already been freed back to the allocator.
+        let mut key = XteaKey(vec![0x1111_1111, 0x2222_2222, 0x3333_3333, 0x4444_4444]);
+        key.clear();
+        assert!(key.0.iter().all(|&word| word == 0));
+    }
+
+    #[cfg(feature = "explicit_clear")]
+    #[test]
+    fn explicit_clear_zeroizes_buffer_on_drop() {
+        let mut bytes = Bytes::new(vec![0xAA; 16]);
+        bytes.clear();
+        assert!(bytes.buffer.iter().all(|&byte| byte == 0));
     }
 }